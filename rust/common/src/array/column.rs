@@ -0,0 +1,23 @@
+use crate::array::ArrayRef;
+use crate::types::DataTypeRef;
+
+/// One column of a [`DataChunk`](crate::array::DataChunk): an array paired with its type.
+#[derive(Clone, Debug)]
+pub struct Column {
+    array: ArrayRef,
+    data_type: DataTypeRef,
+}
+
+impl Column {
+    pub fn new(array: ArrayRef, data_type: DataTypeRef) -> Self {
+        Self { array, data_type }
+    }
+
+    pub fn array(&self) -> &ArrayRef {
+        &self.array
+    }
+
+    pub fn data_type(&self) -> DataTypeRef {
+        self.data_type.clone()
+    }
+}
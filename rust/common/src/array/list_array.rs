@@ -0,0 +1,156 @@
+use crate::array::{ArrayBuilder, ArrayImpl, ArrayRef};
+use crate::error::Result;
+use crate::types::{DataTypeRef, Datum, ScalarImpl};
+
+/// A single SQL `ARRAY` value: an ordered sequence of element datums, all sharing the list's
+/// element type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListValue {
+    values: Vec<Datum>,
+}
+
+impl ListValue {
+    pub fn new(values: Vec<Datum>) -> Self {
+        Self { values }
+    }
+
+    pub fn values(&self) -> &[Datum] {
+        &self.values
+    }
+}
+
+/// Array of nested `ARRAY`/`LIST` values.
+///
+/// Follows the nested-type model arrow2 uses for `List`/`LargeList`/`FixedSizeList`/`Map`: a
+/// single child array holds every element of every row back to back, and an `offsets` buffer
+/// delimits each row's slice of it, so that row `i`'s elements are
+/// `child[offsets[i]..offsets[i + 1]]`. A row is null iff its slot in `bitmap` is `false`,
+/// independent of whether its slice of `child` happens to be empty.
+#[derive(Debug)]
+pub struct ListArray {
+    child: ArrayRef,
+    offsets: Vec<i32>,
+    bitmap: Vec<bool>,
+}
+
+impl ListArray {
+    pub fn child(&self) -> &ArrayRef {
+        &self.child
+    }
+
+    pub fn len(&self) -> usize {
+        self.bitmap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bitmap.is_empty()
+    }
+
+    pub fn value_at(&self, idx: usize) -> Option<ListValue> {
+        if !self.bitmap[idx] {
+            return None;
+        }
+        let start = self.offsets[idx] as usize;
+        let end = self.offsets[idx + 1] as usize;
+        let values = (start..end).map(|i| self.child.datum_at(i)).collect();
+        Some(ListValue::new(values))
+    }
+
+    pub fn datum_at(&self, idx: usize) -> Datum {
+        self.value_at(idx).map(ScalarImpl::List)
+    }
+}
+
+/// Builds a [`ListArray`] one row at a time, accumulating every row's elements into a single
+/// child builder and recording each row's span in `offsets`.
+pub struct ListArrayBuilder {
+    child_builder: Box<dyn ArrayBuilder>,
+    offsets: Vec<i32>,
+    bitmap: Vec<bool>,
+    len: i32,
+}
+
+impl ListArrayBuilder {
+    pub fn new(element_type: DataTypeRef, capacity: usize) -> Result<Self> {
+        Ok(Self {
+            child_builder: element_type.create_array_builder(capacity)?,
+            offsets: vec![0],
+            bitmap: Vec::with_capacity(capacity),
+            len: 0,
+        })
+    }
+}
+
+impl ArrayBuilder for ListArrayBuilder {
+    fn append_datum(&mut self, datum: &Datum) -> Result<()> {
+        match datum {
+            Some(ScalarImpl::List(list)) => {
+                for element in list.values() {
+                    self.child_builder.append_datum(element)?;
+                }
+                self.len += list.values().len() as i32;
+                self.bitmap.push(true);
+            }
+            None => {
+                self.bitmap.push(false);
+            }
+            Some(other) => panic!("expected a list datum, got {:?}", other),
+        }
+        self.offsets.push(self.len);
+        Ok(())
+    }
+
+    fn append_null(&mut self) -> Result<()> {
+        self.append_datum(&None)
+    }
+
+    fn finish(&mut self) -> Result<ArrayImpl> {
+        Ok(ArrayImpl::List(ListArray {
+            child: self.child_builder.finish()?.into(),
+            offsets: std::mem::take(&mut self.offsets),
+            bitmap: std::mem::take(&mut self.bitmap),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Int32Type, Scalar};
+
+    #[test]
+    fn test_list_array_round_trip() {
+        let element_type = Int32Type::create(true);
+        let mut builder = ListArrayBuilder::new(element_type, 3).unwrap();
+        builder
+            .append_datum(&Some(ScalarImpl::List(ListValue::new(vec![
+                Some(1i32.to_scalar_value()),
+                Some(2i32.to_scalar_value()),
+            ]))))
+            .unwrap();
+        builder.append_null().unwrap();
+        builder
+            .append_datum(&Some(ScalarImpl::List(ListValue::new(vec![Some(
+                3i32.to_scalar_value(),
+            )]))))
+            .unwrap();
+        let array = match builder.finish().unwrap() {
+            ArrayImpl::List(array) => array,
+            other => panic!("expected ArrayImpl::List, got {:?}", other),
+        };
+
+        assert_eq!(array.len(), 3);
+        assert_eq!(
+            array.value_at(0),
+            Some(ListValue::new(vec![
+                Some(1i32.to_scalar_value()),
+                Some(2i32.to_scalar_value())
+            ]))
+        );
+        assert_eq!(array.value_at(1), None);
+        assert_eq!(
+            array.value_at(2),
+            Some(ListValue::new(vec![Some(3i32.to_scalar_value())]))
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::types::{Datum, PrimitiveType, Scalar};
+
+pub mod column;
+pub mod list_array;
+
+pub use column::Column;
+
+pub type ArrayRef = Arc<ArrayImpl>;
+
+/// The dynamically-typed array used throughout the crate: one variant per [`DataType`].
+///
+/// [`DataType`]: crate::types::DataType
+#[derive(Debug)]
+pub enum ArrayImpl {
+    Int32(PrimitiveArray<i32>),
+    Float32(PrimitiveArray<f32>),
+    Bool(PrimitiveArray<bool>),
+    Decimal(PrimitiveArray<rust_decimal::Decimal>),
+    List(list_array::ListArray),
+}
+
+impl ArrayImpl {
+    pub fn len(&self) -> usize {
+        match self {
+            ArrayImpl::Int32(a) => a.len(),
+            ArrayImpl::Float32(a) => a.len(),
+            ArrayImpl::Bool(a) => a.len(),
+            ArrayImpl::Decimal(a) => a.len(),
+            ArrayImpl::List(a) => a.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn value_at(&self, idx: usize) -> Datum {
+        match self {
+            ArrayImpl::Int32(a) => a.value_at(idx).map(|v| v.to_scalar_value()),
+            ArrayImpl::Float32(a) => a.value_at(idx).map(|v| v.to_scalar_value()),
+            ArrayImpl::Bool(a) => a.value_at(idx).map(|v| v.to_scalar_value()),
+            ArrayImpl::Decimal(a) => a.value_at(idx).map(|v| v.to_scalar_value()),
+            ArrayImpl::List(a) => a.datum_at(idx),
+        }
+    }
+
+    pub fn datum_at(&self, idx: usize) -> Datum {
+        self.value_at(idx)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Datum> + '_ {
+        (0..self.len()).map(move |i| self.value_at(i))
+    }
+}
+
+/// Builds one [`ArrayImpl`] variant. Every [`DataType`] produces one of these from
+/// `create_array_builder`, so expressions can stay generic over the concrete array type.
+///
+/// [`DataType`]: crate::types::DataType
+pub trait ArrayBuilder {
+    fn append_datum(&mut self, datum: &Datum) -> Result<()>;
+    fn append_null(&mut self) -> Result<()>;
+    fn finish(&mut self) -> Result<ArrayImpl>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PrimitiveArray<T> {
+    data: Vec<Option<T>>,
+}
+
+impl<T: Clone> PrimitiveArray<T> {
+    pub fn from_slice(data: &[Option<T>]) -> Result<Self> {
+        Ok(Self {
+            data: data.to_vec(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn value_at(&self, idx: usize) -> Option<T> {
+        self.data[idx].clone()
+    }
+}
+
+impl<T: PrimitiveType> From<PrimitiveArray<T>> for ArrayImpl {
+    fn from(array: PrimitiveArray<T>) -> Self {
+        T::wrap_array(array)
+    }
+}
+
+pub struct PrimitiveArrayBuilder<T> {
+    data: Vec<Option<T>>,
+}
+
+impl<T> PrimitiveArrayBuilder<T> {
+    pub fn new(capacity: usize) -> Result<Self> {
+        Ok(Self {
+            data: Vec::with_capacity(capacity),
+        })
+    }
+}
+
+impl<T: PrimitiveType> PrimitiveArrayBuilder<T> {
+    pub fn append(&mut self, value: Option<T>) -> Result<()> {
+        self.data.push(value);
+        Ok(())
+    }
+}
+
+impl<T: PrimitiveType> ArrayBuilder for PrimitiveArrayBuilder<T> {
+    fn append_datum(&mut self, datum: &Datum) -> Result<()> {
+        let value = datum.as_ref().map(|scalar| {
+            T::try_from_scalar(scalar)
+                .unwrap_or_else(|| panic!("scalar/array type mismatch: {:?}", scalar))
+        });
+        self.data.push(value);
+        Ok(())
+    }
+
+    fn append_null(&mut self) -> Result<()> {
+        self.data.push(None);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<ArrayImpl> {
+        Ok(T::wrap_array(PrimitiveArray {
+            data: std::mem::take(&mut self.data),
+        }))
+    }
+}
+
+pub type Int32ArrayBuilder = PrimitiveArrayBuilder<i32>;
+/// Alias matching the abbreviated name existing call sites use for `Int32ArrayBuilder`.
+pub type I32ArrayBuilder = Int32ArrayBuilder;
+pub type Float32ArrayBuilder = PrimitiveArrayBuilder<f32>;
+pub type BoolArrayBuilder = PrimitiveArrayBuilder<bool>;
+pub type DecimalArrayBuilder = PrimitiveArrayBuilder<rust_decimal::Decimal>;
+
+/// A batch of columns evaluated and operated on together.
+#[derive(Debug, Default, Clone)]
+pub struct DataChunk {
+    columns: Vec<Column>,
+}
+
+impl DataChunk {
+    pub fn new(columns: Vec<Column>, _visibility: Option<Vec<bool>>) -> Self {
+        Self { columns }
+    }
+
+    pub fn builder() -> DataChunkBuilder {
+        DataChunkBuilder::default()
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Total number of rows, visible or not. Today every row is visible, so this is the same
+    /// as [`DataChunk::cardinality`].
+    pub fn capacity(&self) -> usize {
+        self.columns.first().map(|c| c.array().len()).unwrap_or(0)
+    }
+
+    /// Number of visible rows.
+    pub fn cardinality(&self) -> usize {
+        self.capacity()
+    }
+}
+
+#[derive(Default)]
+pub struct DataChunkBuilder {
+    columns: Vec<Column>,
+}
+
+impl DataChunkBuilder {
+    pub fn columns(mut self, columns: Vec<Column>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    pub fn build(self) -> DataChunk {
+        DataChunk {
+            columns: self.columns,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ErrorCode {
+    InternalError(String),
+}
+
+#[derive(Debug)]
+pub struct RwError {
+    inner: ErrorCode,
+}
+
+impl fmt::Display for RwError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            ErrorCode::InternalError(msg) => write!(f, "internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RwError {}
+
+impl From<ErrorCode> for RwError {
+    fn from(inner: ErrorCode) -> Self {
+        Self { inner }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, RwError>;
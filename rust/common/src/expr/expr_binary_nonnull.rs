@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use risingwave_pb::expr::expr_node::Type as ProstExprType;
+
+use crate::array::{ArrayRef, DataChunk};
+use crate::error::{ErrorCode, Result, RwError};
+use crate::expr::{BoxedExpression, Expression};
+use crate::types::{DataType, DataTypeRef, ScalarImpl};
+
+/// A binary expression whose result is `NULL` whenever either operand is `NULL` (i.e. every
+/// SQL binary operator except a handful of special-cased ones like `IS NOT DISTINCT FROM`).
+#[derive(Debug)]
+struct BinaryExpression {
+    op: ProstExprType,
+    return_type: DataTypeRef,
+    l: BoxedExpression,
+    r: BoxedExpression,
+}
+
+impl Expression for BinaryExpression {
+    fn return_type(&self) -> &dyn DataType {
+        &*self.return_type
+    }
+
+    fn return_type_ref(&self) -> DataTypeRef {
+        self.return_type.clone()
+    }
+
+    fn eval(&mut self, input: &DataChunk) -> Result<ArrayRef> {
+        let lhs = self.l.eval(input)?;
+        let rhs = self.r.eval(input)?;
+        let mut builder = self.return_type.create_array_builder(input.capacity())?;
+        for idx in 0..input.capacity() {
+            let result = match (lhs.value_at(idx), rhs.value_at(idx)) {
+                (Some(l), Some(r)) => Some(apply(self.op, l, r)?),
+                _ => None,
+            };
+            builder.append_datum(&result)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+/// Implements the operators this crate's expressions currently need; extend here as more
+/// binary operators are added.
+fn apply(op: ProstExprType, l: ScalarImpl, r: ScalarImpl) -> Result<ScalarImpl> {
+    match (op, l, r) {
+        (ProstExprType::GreaterThan, ScalarImpl::Int32(l), ScalarImpl::Int32(r)) => {
+            Ok(ScalarImpl::Bool(l > r))
+        }
+        (ProstExprType::LessThanOrEqual, ScalarImpl::Int32(l), ScalarImpl::Float32(r)) => {
+            Ok(ScalarImpl::Bool((l as f32) <= r))
+        }
+        (ProstExprType::Divide, ScalarImpl::Int32(l), ScalarImpl::Int32(r)) => {
+            if r == 0 {
+                Err(RwError::from(ErrorCode::InternalError(
+                    "division by zero".to_string(),
+                )))
+            } else {
+                Ok(ScalarImpl::Int32(l / r))
+            }
+        }
+        (op, l, r) => panic!("unsupported binary op {:?} for ({:?}, {:?})", op, l, r),
+    }
+}
+
+pub fn new_binary_expr(
+    op: ProstExprType,
+    return_type: DataTypeRef,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> BoxedExpression {
+    Box::new(BinaryExpression {
+        op,
+        return_type,
+        l,
+        r,
+    })
+}
@@ -1,9 +1,10 @@
+use crate::array::column::Column;
 use crate::array::{ArrayRef, DataChunk};
 use crate::error::Result;
 use crate::expr::{BoxedExpression, DataType, Expression};
-use crate::types::DataTypeRef;
-use itertools::Itertools;
+use crate::types::{DataTypeRef, Datum};
 
+#[derive(Debug)]
 pub struct WhenClause {
     pub when: BoxedExpression,
     pub then: BoxedExpression,
@@ -15,6 +16,7 @@ impl WhenClause {
     }
 }
 
+#[derive(Debug)]
 pub struct CaseExpression {
     return_type: DataTypeRef,
     when_clauses: Vec<WhenClause>,
@@ -43,40 +45,84 @@ impl Expression for CaseExpression {
         self.return_type.clone()
     }
     fn eval(&mut self, input: &DataChunk) -> Result<ArrayRef> {
-        let mut els = self
-            .else_clause
-            .as_deref_mut()
-            .map(|else_clause| else_clause.eval(input).unwrap());
-        let when_thens = self
-            .when_clauses
-            .iter_mut()
-            .map(|when_clause| {
-                (
-                    when_clause.when.eval(input).unwrap(),
-                    when_clause.then.eval(input).unwrap(),
-                )
-            })
-            .collect_vec();
-        let mut output_array = self
-            .return_type_ref()
-            .create_array_builder(input.capacity())?;
-        for idx in 0..input.capacity() {
-            let t = if let Some((_, t)) = when_thens
-                .iter()
-                .map(|(w, t)| (w.value_at(idx), t.value_at(idx)))
-                .find(|(w, _)| *w.unwrap().into_scalar_impl().as_bool())
-            {
-                Some(t.unwrap().into_scalar_impl())
-            } else {
-                els.as_mut()
-                    .map(|e| e.value_at(idx).unwrap().into_scalar_impl())
-            };
-            output_array.append_datum(&t)?;
+        let len = input.capacity();
+        let mut results: Vec<Datum> = vec![None; len];
+        // Rows that no `when` clause has claimed yet. Every clause (and the final `else`)
+        // only ever sees the subset of rows still in here, so a `then`/`else` arm is never
+        // evaluated on a row it doesn't govern.
+        let mut remaining: Vec<usize> = (0..len).collect();
+
+        for when_clause in &mut self.when_clauses {
+            if remaining.is_empty() {
+                break;
+            }
+            let branch_input = compact_chunk(input, &remaining)?;
+            let when_res = when_clause.when.eval(&branch_input)?;
+
+            let mut matched = Vec::new();
+            let mut still_remaining = Vec::new();
+            for (pos, &row_idx) in remaining.iter().enumerate() {
+                // NULL predicates behave like `false`: the row falls through to the next
+                // `when` (or the final `else`) instead of taking this branch.
+                let is_true = when_res
+                    .value_at(pos)
+                    .map(|v| *v.into_scalar_impl().as_bool())
+                    .unwrap_or(false);
+                if is_true {
+                    matched.push(row_idx);
+                } else {
+                    still_remaining.push(row_idx);
+                }
+            }
+            remaining = still_remaining;
+
+            if !matched.is_empty() {
+                let then_input = compact_chunk(input, &matched)?;
+                let then_res = when_clause.then.eval(&then_input)?;
+                for (pos, &row_idx) in matched.iter().enumerate() {
+                    results[row_idx] = then_res.value_at(pos).map(|v| v.into_scalar_impl());
+                }
+            }
+        }
+
+        if !remaining.is_empty() {
+            if let Some(else_clause) = self.else_clause.as_deref_mut() {
+                let else_input = compact_chunk(input, &remaining)?;
+                let else_res = else_clause.eval(&else_input)?;
+                for (pos, &row_idx) in remaining.iter().enumerate() {
+                    results[row_idx] = else_res.value_at(pos).map(|v| v.into_scalar_impl());
+                }
+            }
+        }
+
+        let mut output_array = self.return_type_ref().create_array_builder(len)?;
+        for datum in &results {
+            output_array.append_datum(datum)?;
         }
         Ok(output_array.finish()?.into())
     }
 }
 
+/// Builds a new `DataChunk` containing only the rows of `input` at `indices`, in that order.
+///
+/// This lets a `when`/`then`/`else` expression evaluate over just the rows it governs, rather
+/// than the full chunk, so branches that would error or do wasted work on rows they don't own
+/// never run on them.
+fn compact_chunk(input: &DataChunk, indices: &[usize]) -> Result<DataChunk> {
+    let columns = input
+        .columns()
+        .iter()
+        .map(|column| {
+            let mut builder = column.data_type().create_array_builder(indices.len())?;
+            for &idx in indices {
+                builder.append_datum(&column.array().datum_at(idx))?;
+            }
+            Ok(Column::new(builder.finish()?.into(), column.data_type()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataChunk::builder().columns(columns).build())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +174,88 @@ mod tests {
         assert_eq!(output.datum_at(3), Some(4.1f32.to_scalar_value()));
         assert_eq!(output.datum_at(4), Some(4.1f32.to_scalar_value()));
     }
+
+    #[test]
+    fn test_case_short_circuit() {
+        // when x > 0 then 10 / x else 0
+        //
+        // The `then` arm divides by `x`, so if it were ever evaluated on the rows that take
+        // the `else` branch (x <= 0, including x == 0), this would panic on division by zero.
+        let ret_type = Int32Type::create(true);
+        let when_clauses = vec![WhenClause::new(
+            new_binary_expr(
+                ProstExprType::GreaterThan,
+                BoolType::create(false),
+                Box::new(InputRefExpression::new(Int32Type::create(false), 0)),
+                Box::new(LiteralExpression::new(
+                    Int32Type::create(false),
+                    Some(0i32.to_scalar_value()),
+                )),
+            ),
+            new_binary_expr(
+                ProstExprType::Divide,
+                Int32Type::create(true),
+                Box::new(LiteralExpression::new(
+                    Int32Type::create(false),
+                    Some(10i32.to_scalar_value()),
+                )),
+                Box::new(InputRefExpression::new(Int32Type::create(false), 0)),
+            ),
+        )];
+        let els = Box::new(LiteralExpression::new(
+            Int32Type::create(true),
+            Some(0i32.to_scalar_value()),
+        ));
+        let mut case_expr = CaseExpression::new(ret_type, when_clauses, Some(els));
+        let col = create_column_i32(&[Some(2), Some(0), Some(-1), Some(0), Some(5)]).unwrap();
+        let input = DataChunk::builder().columns([col].to_vec()).build();
+        let output = case_expr.eval(&input).unwrap();
+        assert_eq!(output.datum_at(0), Some(5i32.to_scalar_value()));
+        assert_eq!(output.datum_at(1), Some(0i32.to_scalar_value()));
+        assert_eq!(output.datum_at(2), Some(0i32.to_scalar_value()));
+        assert_eq!(output.datum_at(3), Some(0i32.to_scalar_value()));
+        assert_eq!(output.datum_at(4), Some(2i32.to_scalar_value()));
+    }
+
+    #[test]
+    fn test_case_with_list_return_type() {
+        use crate::array::list_array::ListValue;
+        use crate::types::{ListType, ScalarImpl};
+
+        // when x > 0 then ARRAY[1, 2] else ARRAY[3]
+        let element_type = Int32Type::create(true);
+        let ret_type = ListType::create(true, element_type);
+        let then_list = ListValue::new(vec![
+            Some(1i32.to_scalar_value()),
+            Some(2i32.to_scalar_value()),
+        ]);
+        let else_list = ListValue::new(vec![Some(3i32.to_scalar_value())]);
+        let when_clauses = vec![WhenClause::new(
+            new_binary_expr(
+                ProstExprType::GreaterThan,
+                BoolType::create(false),
+                Box::new(InputRefExpression::new(Int32Type::create(false), 0)),
+                Box::new(LiteralExpression::new(
+                    Int32Type::create(false),
+                    Some(0i32.to_scalar_value()),
+                )),
+            ),
+            Box::new(LiteralExpression::new(
+                ret_type.clone(),
+                Some(ScalarImpl::List(then_list.clone())),
+            )),
+        )];
+        let els = Box::new(LiteralExpression::new(
+            ret_type.clone(),
+            Some(ScalarImpl::List(else_list.clone())),
+        ));
+        // Drives the real `DataTypeRef::create_array_builder` dispatch for `ListType`, and
+        // round-trips `ListValue`s through `CaseExpression::eval`'s `append_datum`/`datum_at`.
+        let mut case_expr = CaseExpression::new(ret_type, when_clauses, Some(els));
+        let col = create_column_i32(&[Some(1), Some(-1)]).unwrap();
+        let input = DataChunk::builder().columns([col].to_vec()).build();
+        let output = case_expr.eval(&input).unwrap();
+        assert_eq!(output.datum_at(0), Some(ScalarImpl::List(then_list)));
+        assert_eq!(output.datum_at(1), Some(ScalarImpl::List(else_list)));
+    }
 }
\ No newline at end of file
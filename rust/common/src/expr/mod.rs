@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use crate::array::{ArrayRef, DataChunk};
+use crate::error::Result;
+// Re-exported so expression modules can `use crate::expr::{..., DataType, ...}` alongside
+// the other expression-tree types, without also reaching into `crate::types` directly.
+pub use crate::types::DataType;
+use crate::types::{DataTypeRef, Datum};
+
+pub mod expr_binary_nonnull;
+pub mod expr_case;
+pub mod pg_sleep;
+
+/// A expression tree node: evaluates over a [`DataChunk`], producing one array with one
+/// value per input row.
+pub trait Expression: std::fmt::Debug {
+    fn return_type(&self) -> &dyn DataType;
+    fn return_type_ref(&self) -> DataTypeRef;
+    fn eval(&mut self, input: &DataChunk) -> Result<ArrayRef>;
+}
+
+pub type BoxedExpression = Box<dyn Expression>;
+
+/// A constant value, broadcast to every row of the input chunk.
+#[derive(Debug)]
+pub struct LiteralExpression {
+    return_type: DataTypeRef,
+    literal: Datum,
+}
+
+impl LiteralExpression {
+    pub fn new(return_type: DataTypeRef, literal: Datum) -> Self {
+        Self {
+            return_type,
+            literal,
+        }
+    }
+}
+
+impl Expression for LiteralExpression {
+    fn return_type(&self) -> &dyn DataType {
+        &*self.return_type
+    }
+
+    fn return_type_ref(&self) -> DataTypeRef {
+        self.return_type.clone()
+    }
+
+    fn eval(&mut self, input: &DataChunk) -> Result<ArrayRef> {
+        let mut builder = self.return_type.create_array_builder(input.capacity())?;
+        for _ in 0..input.capacity() {
+            builder.append_datum(&self.literal)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+/// References one column of the input chunk by index.
+#[derive(Debug)]
+pub struct InputRefExpression {
+    return_type: DataTypeRef,
+    idx: usize,
+}
+
+impl InputRefExpression {
+    pub fn new(return_type: DataTypeRef, idx: usize) -> Self {
+        Self { return_type, idx }
+    }
+}
+
+impl Expression for InputRefExpression {
+    fn return_type(&self) -> &dyn DataType {
+        &*self.return_type
+    }
+
+    fn return_type_ref(&self) -> DataTypeRef {
+        self.return_type.clone()
+    }
+
+    fn eval(&mut self, input: &DataChunk) -> Result<ArrayRef> {
+        Ok(input.columns()[self.idx].array().clone())
+    }
+}
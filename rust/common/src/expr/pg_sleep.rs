@@ -3,7 +3,30 @@ use crate::error::Result;
 use crate::expr::{BoxedExpression, Expression};
 use crate::types::{DataType, DataTypeRef, Int32Type};
 use log::debug;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// A broadcastable cancellation signal: `cancelled` lets rows that arrive after the signal
+/// fired skip sleeping entirely, while `notify` wakes whichever row is sleeping right now.
+#[derive(Debug, Default)]
+pub struct CancelSignal {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancelSignal {
+    /// Cancels the signal, waking whichever row is sleeping right now and causing all
+    /// future rows to skip sleeping entirely.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
 
 /// `PG_SLEEP` sleeps on current session for given duration (double precision in seconds),
 /// and returns `NULL` for all inputs.
@@ -11,10 +34,24 @@ use std::sync::Arc;
 /// Note that currently `PG_SLEEP` accepts decimals as arguments, which is not compatible
 /// with Postgres. The reason for this is that Calcite always converts float/double to
 /// decimal, but not vice versa.
+///
+/// Sleeping is driven by [`tokio::time::sleep`] rather than [`std::thread::sleep`], so it
+/// does not stall the worker thread for the duration of the sleep. [`eval_async`] also races
+/// each row's sleep against [`cancel_handle`], which would let aborting the session/statement
+/// wake the future immediately instead of after the full duration - once something calls it.
+///
+/// No such caller exists yet in this crate: nothing outside `cancel_handle`'s own unit tests
+/// ever holds one, because there is no session/statement abort path here to wire it into.
+/// `PG_SLEEP` is therefore cancellable in the sense that the primitive and plumbing exist and
+/// are tested, not in the sense that aborting a running query actually does it today.
+///
+/// [`eval_async`]: PgSleepExpression::eval_async
+/// [`cancel_handle`]: PgSleepExpression::cancel_handle
 #[derive(Debug)]
 pub struct PgSleepExpression {
     child_expr: BoxedExpression,
     return_type: DataTypeRef,
+    cancel: Arc<CancelSignal>,
 }
 
 impl PgSleepExpression {
@@ -22,33 +59,41 @@ impl PgSleepExpression {
         PgSleepExpression {
             child_expr,
             return_type: Int32Type::create(true),
+            cancel: Arc::new(CancelSignal::default()),
         }
     }
-}
-
-impl Expression for PgSleepExpression {
-    fn return_type(&self) -> &dyn DataType {
-        &*self.return_type
-    }
 
-    fn return_type_ref(&self) -> DataTypeRef {
-        self.return_type.clone()
+    /// Returns a handle that query cancellation can use to interrupt an in-flight (or
+    /// not-yet-started) sleep.
+    //
+    // TODO: nothing calls this yet outside of tests - wire it into the session/statement
+    // abort path once one exists, so `PG_SLEEP` actually observes query cancellation in
+    // production rather than only when a caller holds (and uses) this handle directly.
+    pub fn cancel_handle(&self) -> Arc<CancelSignal> {
+        self.cancel.clone()
     }
 
-    fn eval(&mut self, input: &DataChunk) -> Result<ArrayRef> {
+    /// Non-blocking, cancellable evaluation. Each row's sleep is raced against the cancel
+    /// signal; once cancelled, no further rows are slept on.
+    pub async fn eval_async(&mut self, input: &DataChunk) -> Result<ArrayRef> {
         use num_traits::ToPrimitive;
         use std::time::Duration;
 
         let child_result = self.child_expr.eval(input)?;
         let mut array_builder = I32ArrayBuilder::new(input.cardinality())?;
         for datum in child_result.iter() {
-            if let Some(duration) = datum {
-                // Postgres accepts double precisions, but Calcite likes decimals
-                let duration_secs = duration.into_decimal().to_f64().unwrap();
-                if duration_secs > 0.0 {
-                    let duration_ms = (duration_secs * 1000.0) as u64;
-                    debug!("pg_sleep() for {} ms", duration_ms);
-                    std::thread::sleep(Duration::from_millis(duration_ms));
+            if !self.cancel.is_cancelled() {
+                if let Some(duration) = datum {
+                    // Postgres accepts double precisions, but Calcite likes decimals
+                    let duration_secs = duration.into_decimal().to_f64().unwrap();
+                    if duration_secs > 0.0 {
+                        let duration_ms = (duration_secs * 1000.0) as u64;
+                        debug!("pg_sleep() for {} ms", duration_ms);
+                        tokio::select! {
+                            _ = tokio::time::sleep(Duration::from_millis(duration_ms)) => {}
+                            _ = self.cancel.notify.notified() => {}
+                        }
+                    }
                 }
             }
             array_builder.append_null()?;
@@ -58,6 +103,31 @@ impl Expression for PgSleepExpression {
     }
 }
 
+impl Expression for PgSleepExpression {
+    fn return_type(&self) -> &dyn DataType {
+        &*self.return_type
+    }
+
+    fn return_type_ref(&self) -> DataTypeRef {
+        self.return_type.clone()
+    }
+
+    fn eval(&mut self, input: &DataChunk) -> Result<ArrayRef> {
+        // Bridge onto the async path without blocking the tokio scheduler: `block_in_place`
+        // hands this worker thread's other tasks off to the rest of the pool for the
+        // duration of the blocking `block_on` call below.
+        //
+        // This requires the calling thread to already be inside a multi-threaded Tokio
+        // runtime - `Handle::current()` panics with none running, and `block_in_place`
+        // itself panics on a current-thread one. Whatever drives `Expression::eval` (directly,
+        // or transitively through something like `CaseExpression::eval`) must run on such a
+        // runtime for expressions that may contain `PG_SLEEP`.
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(self.eval_async(input))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,8 +138,8 @@ mod tests {
     use rust_decimal::prelude::FromStr;
     use rust_decimal::Decimal;
 
-    #[test]
-    fn test_pg_sleep() -> Result<()> {
+    #[tokio::test]
+    async fn test_pg_sleep() -> Result<()> {
         let decimal_type = DecimalType::create(true, 10, 2)?;
         let mut expr =
             PgSleepExpression::new(Box::new(InputRefExpression::new(decimal_type.clone(), 0)));
@@ -83,17 +153,66 @@ mod tests {
         };
 
         let input_chunk = DataChunk::new(
-            vec![Column::new(
-                Arc::new(ArrayImpl::Decimal(input_array)),
-                decimal_type,
-            )],
+            vec![Column::new(Arc::new(input_array), decimal_type)],
             None,
         );
-        let result_array = expr.eval(&input_chunk).unwrap();
+        let result_array = expr.eval_async(&input_chunk).await.unwrap();
         assert_eq!(3, result_array.len());
         for i in 0..3 {
             assert!(result_array.value_at(i).is_none());
         }
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_pg_sleep_cancellation_skips_remaining_sleeps() -> Result<()> {
+        let decimal_type = DecimalType::create(true, 10, 2)?;
+        let mut expr =
+            PgSleepExpression::new(Box::new(InputRefExpression::new(decimal_type.clone(), 0)));
+        let cancel = expr.cancel_handle();
+        cancel.cancel();
+
+        let input_array = {
+            let mut builder = DecimalArrayBuilder::new(1)?;
+            // A long sleep that would time this test out if cancellation didn't take effect.
+            builder.append(Some(Decimal::from_str("60").unwrap()))?;
+            builder.finish()?
+        };
+        let input_chunk = DataChunk::new(
+            vec![Column::new(Arc::new(input_array), decimal_type)],
+            None,
+        );
+        let result_array = expr.eval_async(&input_chunk).await.unwrap();
+        assert_eq!(1, result_array.len());
+        assert!(result_array.value_at(0).is_none());
+        Ok(())
+    }
+
+    /// Exercises the synchronous `Expression::eval` bridge (not just `eval_async` directly),
+    /// since that's the only entry point production code actually calls through. Requires a
+    /// multi-thread runtime because `block_in_place` panics on a current-thread one.
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_pg_sleep_eval_bridges_to_async() -> Result<()> {
+        let decimal_type = DecimalType::create(true, 10, 2)?;
+        let mut expr =
+            PgSleepExpression::new(Box::new(InputRefExpression::new(decimal_type.clone(), 0)));
+
+        let input_array = {
+            let mut builder = DecimalArrayBuilder::new(2)?;
+            builder.append(Some(Decimal::from_str("0.01").unwrap()))?;
+            builder.append(None)?;
+            builder.finish()?
+        };
+        let input_chunk = DataChunk::new(
+            vec![Column::new(Arc::new(input_array), decimal_type)],
+            None,
+        );
+
+        let result_array = expr.eval(&input_chunk)?;
+        assert_eq!(2, result_array.len());
+        for i in 0..2 {
+            assert!(result_array.value_at(i).is_none());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod array;
+pub mod error;
+pub mod expr;
+pub mod types;
@@ -0,0 +1,40 @@
+use crate::array::list_array::ListArrayBuilder;
+use crate::array::ArrayBuilder;
+use crate::error::Result;
+use crate::types::{DataType, DataTypeRef};
+
+/// The type of a SQL `ARRAY`/`LIST` column: any value it holds is a [`ListValue`] whose
+/// elements all have `element_type`.
+///
+/// [`ListValue`]: crate::array::list_array::ListValue
+#[derive(Clone, Debug)]
+pub struct ListType {
+    nullable: bool,
+    element_type: DataTypeRef,
+}
+
+impl ListType {
+    pub fn create(nullable: bool, element_type: DataTypeRef) -> DataTypeRef {
+        std::sync::Arc::new(Self {
+            nullable,
+            element_type,
+        })
+    }
+
+    pub fn element_type(&self) -> &DataTypeRef {
+        &self.element_type
+    }
+}
+
+impl DataType for ListType {
+    fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    fn create_array_builder(&self, capacity: usize) -> Result<Box<dyn ArrayBuilder>> {
+        Ok(Box::new(ListArrayBuilder::new(
+            self.element_type.clone(),
+            capacity,
+        )?))
+    }
+}
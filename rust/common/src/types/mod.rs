@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::array::{ArrayBuilder, ArrayImpl, PrimitiveArray, PrimitiveArrayBuilder};
+use crate::error::Result;
+
+pub mod list_type;
+pub mod numeric;
+
+pub use list_type::ListType;
+
+/// A SQL column type: knows whether it admits `NULL` and how to build an array of its own
+/// values.
+///
+/// Bounded `Send + Sync` so `DataTypeRef` (`Arc<dyn DataType>`) can be shared across the
+/// worker threads of the crate's multi-threaded Tokio runtime.
+pub trait DataType: std::fmt::Debug + Send + Sync {
+    fn is_nullable(&self) -> bool;
+    fn create_array_builder(&self, capacity: usize) -> Result<Box<dyn ArrayBuilder>>;
+}
+
+pub type DataTypeRef = Arc<dyn DataType>;
+
+/// An owned, dynamically-typed scalar value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScalarImpl {
+    Int32(i32),
+    Float32(f32),
+    Bool(bool),
+    Decimal(Decimal),
+    List(crate::array::list_array::ListValue),
+}
+
+/// A nullable scalar, as stored in an array or produced by expression evaluation.
+pub type Datum = Option<ScalarImpl>;
+
+impl ScalarImpl {
+    /// This crate does not (yet) distinguish owned `ScalarImpl` from a borrowed
+    /// `ScalarRefImpl`, so converting between them is the identity; call sites that
+    /// already assume the distinction (e.g. after `ArrayImpl::value_at`) keep working unchanged
+    /// once a real borrowed variant is introduced.
+    pub fn into_scalar_impl(self) -> ScalarImpl {
+        self
+    }
+
+    pub fn as_bool(&self) -> &bool {
+        match self {
+            ScalarImpl::Bool(b) => b,
+            other => panic!("expected a bool scalar, got {:?}", other),
+        }
+    }
+
+    pub fn into_decimal(self) -> Decimal {
+        match self {
+            ScalarImpl::Decimal(d) => d,
+            other => panic!("expected a decimal scalar, got {:?}", other),
+        }
+    }
+}
+
+/// An owned value that can be converted into a [`ScalarImpl`].
+pub trait Scalar: Clone {
+    fn to_scalar_value(self) -> ScalarImpl;
+}
+
+/// A Rust type backing one [`ArrayImpl`]/[`ScalarImpl`] variant (e.g. `i32` backs
+/// `ArrayImpl::Int32`/`ScalarImpl::Int32`).
+pub trait PrimitiveType: Scalar + std::fmt::Debug + PartialEq {
+    fn try_from_scalar(scalar: &ScalarImpl) -> Option<Self>;
+    fn wrap_array(array: PrimitiveArray<Self>) -> ArrayImpl;
+}
+
+macro_rules! impl_primitive_type {
+    ($ty:ty, $variant:ident) => {
+        impl Scalar for $ty {
+            fn to_scalar_value(self) -> ScalarImpl {
+                ScalarImpl::$variant(self)
+            }
+        }
+
+        impl PrimitiveType for $ty {
+            fn try_from_scalar(scalar: &ScalarImpl) -> Option<Self> {
+                match scalar {
+                    ScalarImpl::$variant(v) => Some(v.clone()),
+                    _ => None,
+                }
+            }
+
+            fn wrap_array(array: PrimitiveArray<Self>) -> ArrayImpl {
+                ArrayImpl::$variant(array)
+            }
+        }
+    };
+}
+
+impl_primitive_type!(i32, Int32);
+impl_primitive_type!(f32, Float32);
+impl_primitive_type!(bool, Bool);
+impl_primitive_type!(Decimal, Decimal);
+
+macro_rules! impl_data_type {
+    ($name:ident, $ty:ty) => {
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            nullable: bool,
+        }
+
+        impl $name {
+            pub fn create(nullable: bool) -> DataTypeRef {
+                Arc::new(Self { nullable })
+            }
+        }
+
+        impl DataType for $name {
+            fn is_nullable(&self) -> bool {
+                self.nullable
+            }
+
+            fn create_array_builder(&self, capacity: usize) -> Result<Box<dyn ArrayBuilder>> {
+                Ok(Box::new(PrimitiveArrayBuilder::<$ty>::new(capacity)?))
+            }
+        }
+    };
+}
+
+impl_data_type!(Int32Type, i32);
+impl_data_type!(Float32Type, f32);
+impl_data_type!(BoolType, bool);
+
+#[derive(Clone, Debug)]
+pub struct DecimalType {
+    nullable: bool,
+    precision: u32,
+    scale: u32,
+}
+
+impl DecimalType {
+    pub fn create(nullable: bool, precision: u32, scale: u32) -> Result<DataTypeRef> {
+        Ok(Arc::new(Self {
+            nullable,
+            precision,
+            scale,
+        }))
+    }
+
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Encodes `value` using the Postgres binary `numeric` wire format - the binary-format
+    /// counterpart to the text form given by `Decimal`'s `Display`/`FromStr` impls. Nothing in
+    /// this crate dispatches to it; callers invoke it directly.
+    pub fn encode_binary(&self, value: &Decimal) -> Vec<u8> {
+        numeric::decimal_to_pg_binary(value)
+    }
+
+    /// Decodes a `Decimal` from the Postgres binary `numeric` wire format - the binary-format
+    /// counterpart to parsing the text form with `FromStr`. Nothing in this crate dispatches to
+    /// it; callers invoke it directly.
+    pub fn decode_binary(&self, bytes: &[u8]) -> Result<Decimal> {
+        numeric::decimal_from_pg_binary(bytes)
+    }
+}
+
+impl DataType for DecimalType {
+    fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    fn create_array_builder(&self, capacity: usize) -> Result<Box<dyn ArrayBuilder>> {
+        Ok(Box::new(PrimitiveArrayBuilder::<Decimal>::new(capacity)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_decimal_type_binary_round_trip() {
+        let decimal_type = DecimalType {
+            nullable: true,
+            precision: 10,
+            scale: 2,
+        };
+        let value = Decimal::from_str("123.45").unwrap();
+        let bytes = decimal_type.encode_binary(&value);
+        let decoded = decimal_type.decode_binary(&bytes).unwrap();
+        assert_eq!(value, decoded);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::error::{ErrorCode, Result, RwError};
+use rust_decimal::Decimal;
+
+/// Number of decimal digits packed into each base-10000 "digit" of the Postgres
+/// `numeric` binary wire format.
+const DEC_DIGITS: u32 = 4;
+const NBASE: i128 = 10_000;
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+
+/// Encodes a [`Decimal`] using the Postgres binary `numeric` wire format.
+///
+/// The layout is a header of four `i16` fields - `ndigits`, `weight`, `sign`, `dscale` -
+/// followed by `ndigits` big-endian base-10000 groups, each in `[0, 9999]`. `weight` is the
+/// power-of-10000 position of the most significant group, so the integer part spans
+/// `weight + 1` groups.
+pub fn decimal_to_pg_binary(value: &Decimal) -> Vec<u8> {
+    let sign = if value.is_sign_negative() {
+        NUMERIC_NEG
+    } else {
+        NUMERIC_POS
+    };
+    let dscale = value.scale() as u16;
+
+    if value.mantissa() == 0 {
+        return encode_header(0, 0, sign, dscale);
+    }
+
+    // Pad the fractional part with trailing zeros so it spans a whole number of base-10000
+    // groups, i.e. so the decimal point falls exactly on a group boundary.
+    let frac_pad = (DEC_DIGITS - value.scale() % DEC_DIGITS) % DEC_DIGITS;
+    let mut mantissa = value.mantissa().unsigned_abs();
+    mantissa *= 10u128.pow(frac_pad);
+    let frac_groups = (value.scale() + frac_pad) / DEC_DIGITS;
+
+    // Split into base-10000 groups, least-significant first, then make sure there are
+    // enough groups to cover the fractional part plus at least one integer group.
+    let mut groups = Vec::new();
+    while mantissa > 0 {
+        groups.push((mantissa % NBASE as u128) as u16);
+        mantissa /= NBASE as u128;
+    }
+    while (groups.len() as u32) < frac_groups + 1 {
+        groups.push(0);
+    }
+    groups.reverse(); // most-significant group first
+
+    let mut weight = groups.len() as i32 - frac_groups as i32 - 1;
+
+    // Leading all-zero groups carry no information; dropping one shifts `weight` down by
+    // one, since the new first group occupies the next lower power-of-10000 position.
+    while groups.len() > 1 && groups[0] == 0 {
+        groups.remove(0);
+        weight -= 1;
+    }
+    // Trailing all-zero groups are likewise redundant - `dscale`, not `ndigits`, records
+    // the declared precision.
+    while groups.len() > 1 && *groups.last().unwrap() == 0 {
+        groups.pop();
+    }
+
+    let mut bytes = encode_header(groups.len() as i16, weight as i16, sign, dscale);
+    for group in groups {
+        bytes.extend_from_slice(&group.to_be_bytes());
+    }
+    bytes
+}
+
+fn encode_header(ndigits: i16, weight: i16, sign: u16, dscale: u16) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8);
+    bytes.extend_from_slice(&ndigits.to_be_bytes());
+    bytes.extend_from_slice(&weight.to_be_bytes());
+    bytes.extend_from_slice(&sign.to_be_bytes());
+    bytes.extend_from_slice(&dscale.to_be_bytes());
+    bytes
+}
+
+/// Decodes a [`Decimal`] from the Postgres binary `numeric` wire format produced by
+/// [`decimal_to_pg_binary`].
+pub fn decimal_from_pg_binary(bytes: &[u8]) -> Result<Decimal> {
+    if bytes.len() < 8 {
+        return Err(RwError::from(ErrorCode::InternalError(format!(
+            "invalid pg numeric binary: header too short ({} bytes)",
+            bytes.len()
+        ))));
+    }
+    let raw_ndigits = i16::from_be_bytes([bytes[0], bytes[1]]);
+    let weight = i16::from_be_bytes([bytes[2], bytes[3]]) as i32;
+    let sign = u16::from_be_bytes([bytes[4], bytes[5]]);
+    let raw_dscale = i16::from_be_bytes([bytes[6], bytes[7]]);
+
+    if sign == NUMERIC_NAN {
+        return Err(RwError::from(ErrorCode::InternalError(
+            "pg numeric NaN has no Decimal representation".to_string(),
+        )));
+    }
+    // `ndigits` indexes into `bytes` below and `dscale` is handed straight to
+    // `Decimal::from_i128_with_scale` (which asserts scale <= 28), so a malformed negative
+    // `ndigits` or out-of-range `dscale` off the wire must be rejected here rather than
+    // overflowing the length arithmetic below or panicking deeper in.
+    if raw_ndigits < 0 {
+        return Err(RwError::from(ErrorCode::InternalError(format!(
+            "invalid pg numeric binary: negative ndigits ({})",
+            raw_ndigits
+        ))));
+    }
+    if !(0..=28).contains(&raw_dscale) {
+        return Err(RwError::from(ErrorCode::InternalError(format!(
+            "invalid pg numeric binary: dscale {} out of supported range 0..=28",
+            raw_dscale
+        ))));
+    }
+    let ndigits = raw_ndigits as usize;
+    let dscale = raw_dscale as u32;
+    if bytes.len() != 8 + ndigits * 2 {
+        return Err(RwError::from(ErrorCode::InternalError(format!(
+            "invalid pg numeric binary: expected {} bytes for {} digits, got {}",
+            8 + ndigits * 2,
+            ndigits,
+            bytes.len()
+        ))));
+    }
+
+    // Horner's method over the base-10000 groups reconstructs the unscaled integer. `ndigits`
+    // is only bounded above by `i16::MAX` so far, and as few as 10 groups (nowhere near that)
+    // is enough to overflow an `i128` accumulator; use checked arithmetic so a pathological
+    // `ndigits` is rejected here instead of panicking (or silently wrapping in release).
+    let mut mantissa: i128 = 0;
+    for i in 0..ndigits {
+        let group = u16::from_be_bytes([bytes[8 + i * 2], bytes[9 + i * 2]]) as i128;
+        mantissa = mantissa
+            .checked_mul(NBASE)
+            .and_then(|m| m.checked_add(group))
+            .ok_or_else(|| {
+                RwError::from(ErrorCode::InternalError(format!(
+                    "invalid pg numeric binary: {} digit groups overflow an i128 mantissa",
+                    ndigits
+                )))
+            })?;
+    }
+
+    // `mantissa` is currently scaled so its decimal point sits after `weight + 1` groups;
+    // shift it so the decimal point instead sits `dscale` digits from the right.
+    let digits_scale = (ndigits as i32 - weight - 1) * DEC_DIGITS as i32;
+    let shift = digits_scale - dscale as i32;
+    // `weight`/`dscale` come straight off the wire, so a malicious or malformed client could
+    // send a `weight` wildly inconsistent with `ndigits` (e.g. `ndigits=1, weight=32000`) and
+    // drive `shift` into the tens of thousands - well past what an i128 mantissa can represent,
+    // which would overflow `10i128.pow` below. Bound it to the digit capacity of i128 instead.
+    const MAX_SHIFT: u32 = 38;
+    if shift.unsigned_abs() > MAX_SHIFT {
+        return Err(RwError::from(ErrorCode::InternalError(format!(
+            "invalid pg numeric binary: weight {} inconsistent with {} digits and dscale {}",
+            weight, ndigits, dscale
+        ))));
+    }
+    match shift.cmp(&0) {
+        std::cmp::Ordering::Greater => mantissa /= 10i128.pow(shift as u32),
+        std::cmp::Ordering::Less => mantissa *= 10i128.pow((-shift) as u32),
+        std::cmp::Ordering::Equal => {}
+    }
+    if sign == NUMERIC_NEG {
+        mantissa = -mantissa;
+    }
+    Ok(Decimal::from_i128_with_scale(mantissa, dscale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn assert_round_trip(s: &str) {
+        let value = Decimal::from_str(s).unwrap();
+        let bytes = decimal_to_pg_binary(&value);
+        let decoded = decimal_from_pg_binary(&bytes).unwrap();
+        assert_eq!(value, decoded, "round trip failed for {}", s);
+    }
+
+    #[test]
+    fn test_decimal_pg_binary_round_trip() {
+        assert_round_trip("0");
+        assert_round_trip("0.00");
+        assert_round_trip("1");
+        assert_round_trip("-1");
+        assert_round_trip("123.45");
+        assert_round_trip("-123.45");
+        assert_round_trip("100");
+        assert_round_trip("0.0001");
+        assert_round_trip("10000.0001");
+        assert_round_trip("12345678901234.56789");
+    }
+
+    #[test]
+    fn test_decimal_pg_binary_rejects_nan() {
+        let nan_bytes = encode_header(0, 0, NUMERIC_NAN, 0);
+        assert!(decimal_from_pg_binary(&nan_bytes).is_err());
+    }
+
+    #[test]
+    fn test_decimal_pg_binary_rejects_inconsistent_weight() {
+        // One digit group but a `weight` claiming it sits far out of range - a client sending
+        // this should get an error, not an overflow panic out of `10i128.pow`.
+        let mut bytes = encode_header(1, 32000, NUMERIC_POS, 0);
+        bytes.extend_from_slice(&1u16.to_be_bytes());
+        assert!(decimal_from_pg_binary(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decimal_pg_binary_rejects_negative_ndigits() {
+        let bytes = encode_header(-1, 0, NUMERIC_POS, 0);
+        assert!(decimal_from_pg_binary(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decimal_pg_binary_rejects_mantissa_overflow() {
+        // 40 groups of 9999 overflows an i128 accumulator well before `ndigits` gets anywhere
+        // near `i16::MAX` - this must be rejected, not panic (or silently wrap in release).
+        let ndigits = 40;
+        let mut bytes = encode_header(ndigits, ndigits - 1, NUMERIC_POS, 0);
+        for _ in 0..ndigits {
+            bytes.extend_from_slice(&9999u16.to_be_bytes());
+        }
+        assert!(decimal_from_pg_binary(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decimal_pg_binary_rejects_out_of_range_dscale() {
+        // `Decimal::from_i128_with_scale` asserts scale <= 28; anything beyond that must be
+        // rejected here instead of panicking inside it.
+        let bytes = encode_header(0, 0, NUMERIC_POS, 29);
+        assert!(decimal_from_pg_binary(&bytes).is_err());
+
+        // -1 as a raw i16 bit pattern, i.e. a negative `dscale`.
+        let bytes = encode_header(0, 0, NUMERIC_POS, 0xFFFF);
+        assert!(decimal_from_pg_binary(&bytes).is_err());
+    }
+}